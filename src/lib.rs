@@ -1,10 +1,18 @@
 mod utils;
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::{HtmlCanvasElement, CanvasRenderingContext2d, KeyboardEvent};
+use web_sys::{AudioBuffer, AudioContext, Gamepad, GamepadButton, HtmlCanvasElement, CanvasRenderingContext2d, KeyboardEvent, Navigator};
+
+// Fixed-timestep physics: all gameplay constants are tuned against this
+// step, regardless of the display's actual refresh rate.
+const DT: f64 = 1000.0 / 60.0;
+const MAX_FRAME_STEPS: u32 = 5;
+// Ignore stick drift near center; below this the axis reads as neutral.
+const GAMEPAD_DEADZONE: f64 = 0.2;
 
 #[derive(PartialEq)]
 enum GameState {
@@ -15,13 +23,15 @@ enum GameState {
 struct Player {
     x: f64,
     y: f64,
+    prev_x: f64,
+    prev_y: f64,
     width: f64,
     height: f64,
     velocity_x: f64,
     velocity_y: f64,
     is_jumping: bool,
-    is_moving_left: bool,
-    is_moving_right: bool,
+    keyboard_left: bool,
+    keyboard_right: bool,
 }
 
 impl Player {
@@ -29,19 +39,39 @@ impl Player {
         Self {
             x,
             y,
+            prev_x: x,
+            prev_y: y,
             width: 50.0,
             height: 50.0,
             velocity_x: 0.0,
             velocity_y: 0.0,
             is_jumping: false,
-            is_moving_left: false,
-            is_moving_right: false,
+            keyboard_left: false,
+            keyboard_right: false,
         }
     }
 
-    fn draw(&self, context: &CanvasRenderingContext2d, camera_y: f64) {
+    fn draw(&self, context: &CanvasRenderingContext2d, camera_y: f64, alpha: f64) {
+        let x = self.prev_x + (self.x - self.prev_x) * alpha;
+        let y = self.prev_y + (self.y - self.prev_y) * alpha;
         context.set_fill_style_str("green");
-        context.fill_rect(self.x, self.y - camera_y, self.width, self.height);
+        context.fill_rect(x, y - camera_y, self.width, self.height);
+    }
+}
+
+// Surface behavior for a block: a flat solid/one-way top, or a sloped ramp.
+#[derive(serde::Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum BlockKind {
+    Solid,
+    OneWay,
+    SlopeLeft,
+    SlopeRight,
+}
+
+impl Default for BlockKind {
+    fn default() -> Self {
+        BlockKind::Solid
     }
 }
 
@@ -50,16 +80,181 @@ struct Block {
     y: f64,
     width: f64,
     height: f64,
+    kind: BlockKind,
 }
 
 impl Block {
-    fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
-        Self { x, y, width, height }
+    fn new(x: f64, y: f64, width: f64, height: f64, kind: BlockKind) -> Self {
+        Self { x, y, width, height, kind }
+    }
+
+    // Surface height (smaller y = higher) above x; flat except on slopes.
+    fn surface_y_at(&self, x: f64) -> f64 {
+        let t = ((x - self.x) / self.width).clamp(0.0, 1.0);
+        match self.kind {
+            BlockKind::SlopeRight => self.y + self.height * (1.0 - t),
+            BlockKind::SlopeLeft => self.y + self.height * t,
+            BlockKind::Solid | BlockKind::OneWay => self.y,
+        }
     }
 
     fn draw(&self, context: &CanvasRenderingContext2d, camera_y: f64) {
-        context.set_fill_style_str("brown");
-        context.fill_rect(self.x, self.y - camera_y, self.width, self.height);
+        let top = self.y - camera_y;
+        match self.kind {
+            BlockKind::Solid => {
+                context.set_fill_style_str("brown");
+                context.fill_rect(self.x, top, self.width, self.height);
+            }
+            BlockKind::OneWay => {
+                context.set_fill_style_str("sienna");
+                context.fill_rect(self.x, top, self.width, self.height * 0.3);
+            }
+            BlockKind::SlopeLeft | BlockKind::SlopeRight => {
+                let (high_x, low_x) = match self.kind {
+                    BlockKind::SlopeRight => (self.x + self.width, self.x),
+                    _ => (self.x, self.x + self.width),
+                };
+                context.set_fill_style_str("brown");
+                context.begin_path();
+                context.move_to(low_x, top + self.height);
+                context.line_to(high_x, top);
+                context.line_to(high_x, top + self.height);
+                context.line_to(low_x, top + self.height);
+                context.close_path();
+                context.fill();
+            }
+        }
+    }
+}
+
+// A hand-authored block for an intro section or difficulty preset.
+#[derive(serde::Deserialize, Clone)]
+struct BlockDef {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    #[serde(default)]
+    kind: BlockKind,
+}
+
+// Gameplay tuning, loaded from JSON5; omitted fields fall back to the hardcoded default.
+#[derive(serde::Deserialize, Clone)]
+#[serde(default)]
+struct Config {
+    gravity: f64,
+    move_speed: f64,
+    jump_velocity: f64,
+    block_width: f64,
+    block_height: f64,
+    vertical_gap: f64,
+    max_jump_dist: f64,
+    screen_margin: f64,
+    blocks: Option<Vec<BlockDef>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            gravity: 0.5,
+            move_speed: 5.0,
+            jump_velocity: -15.0,
+            block_width: 100.0,
+            block_height: 20.0,
+            vertical_gap: 120.0,
+            max_jump_dist: 200.0,
+            screen_margin: 50.0,
+            blocks: None,
+        }
+    }
+}
+
+// Deterministic block placement: a splitmix64 PRNG seeded per run.
+struct LevelGenerator {
+    state: u64,
+    width: u32,
+    config: Config,
+    // Last next_block's reachability window (min_x, max_x, y), for the debug overlay.
+    last_window: Option<(f64, f64, f64)>,
+}
+
+impl LevelGenerator {
+    fn new(seed: u64, width: u32, config: Config) -> Self {
+        Self { state: seed, width, config, last_window: None }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // A uniform value in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_block(&mut self, last: &Block, difficulty: f64) -> Block {
+        let y = last.y - self.config.vertical_gap;
+
+        let tightness = (difficulty * 0.01).min(0.6);
+        let jump_dist = self.config.max_jump_dist * (1.0 - tightness);
+        let width = (self.config.block_width - difficulty * 4.0).max(40.0);
+
+        let relative_min_x = last.x - jump_dist;
+        let relative_max_x = last.x + last.width + jump_dist;
+
+        let screen_margin = self.config.screen_margin;
+        let screen_min_x = screen_margin;
+        let screen_max_x = self.width as f64 - width - screen_margin;
+
+        let min_x = relative_min_x.max(screen_min_x);
+        let max_x = relative_max_x.min(screen_max_x);
+        self.last_window = Some((min_x, max_x, y));
+
+        let x = if min_x < max_x {
+            min_x + self.next_f64() * (max_x - min_x)
+        } else {
+            // Fallback to center if the range is invalid
+            (self.width as f64 - width) / 2.0
+        };
+
+        Block::new(x, y, width, self.config.block_height, BlockKind::Solid)
+    }
+}
+
+// Decoded samples are cached by name so `play` can fire them instantly.
+struct Audio {
+    context: AudioContext,
+    buffers: RefCell<HashMap<String, AudioBuffer>>,
+}
+
+impl Audio {
+    // None if the browser doesn't support AudioContext; callers treat that as sound-unavailable.
+    fn new() -> Option<Self> {
+        Some(Self {
+            context: AudioContext::new().ok()?,
+            buffers: RefCell::new(HashMap::new()),
+        })
+    }
+
+    fn play(&self, name: &str) {
+        let buffers = self.buffers.borrow();
+        let Some(buffer) = buffers.get(name) else {
+            return;
+        };
+        if let Ok(source) = self.context.create_buffer_source() {
+            source.set_buffer(Some(buffer));
+            let _ = source.connect_with_audio_node(&self.context.destination());
+            let _ = source.start();
+        }
+    }
+
+    // Call from the first click/keypress handler to lift the browser's autoplay block.
+    fn resume(&self) {
+        let _ = self.context.resume();
     }
 }
 
@@ -72,19 +267,37 @@ struct Game {
     height: u32,
     camera_y: f64,
     score: i32,
+    last_time: f64,
+    accumulator: f64,
+    config: Config,
+    seed: u64,
+    generator: LevelGenerator,
+    debug: bool,
+    fps: f64,
+    gamepad_left: bool,
+    gamepad_right: bool,
 }
 
 impl Game {
-    fn new(context: CanvasRenderingContext2d, width: u32, height: u32) -> Self {
+    fn new(context: CanvasRenderingContext2d, width: u32, height: u32, config: Config, seed: u64) -> Self {
+        let mut generator = LevelGenerator::new(seed, width, config.clone());
+
         let mut blocks = Vec::new();
         // Create the ground block
-        blocks.push(Block::new(0.0, (height - 20) as f64, width as f64, 20.0));
+        blocks.push(Block::new(0.0, (height - 20) as f64, width as f64, 20.0, BlockKind::Solid));
 
-        // Create random blocks
-        for i in 1..10 {
-            let y = (height - 120 * i) as f64;
-            let x = js_sys::Math::random() * (width - 100) as f64;
-            blocks.push(Block::new(x, y, 100.0, 20.0));
+        if let Some(defs) = &config.blocks {
+            // Hand-authored intro section / preset layout.
+            for def in defs {
+                blocks.push(Block::new(def.x, def.y, def.width, def.height, def.kind));
+            }
+        } else {
+            // Create random blocks
+            for i in 1..10 {
+                let y = height as f64 - config.vertical_gap * i as f64;
+                let x = generator.next_f64() * (width as f64 - config.block_width);
+                blocks.push(Block::new(x, y, config.block_width, config.block_height, BlockKind::Solid));
+            }
         }
 
         Self {
@@ -96,12 +309,95 @@ impl Game {
             height,
             camera_y: 0.0,
             score: 0,
+            last_time: 0.0,
+            accumulator: 0.0,
+            config,
+            seed,
+            generator,
+            debug: false,
+            fps: 0.0,
+            gamepad_left: false,
+            gamepad_right: false,
         }
     }
 
     fn resize(&mut self, width: u32, height: u32) {
         self.width = width;
         self.height = height;
+        self.generator.width = width;
+    }
+
+    // Advances the accumulator, running up to MAX_FRAME_STEPS fixed updates; returns the leftover interpolation alpha.
+    fn advance(&mut self, now: f64) -> f64 {
+        if self.last_time == 0.0 {
+            self.last_time = now;
+        }
+
+        let elapsed = now - self.last_time;
+        self.last_time = now;
+        self.accumulator += elapsed;
+        if elapsed > 0.0 {
+            self.fps = 1000.0 / elapsed;
+        }
+
+        let mut steps = 0;
+        while self.accumulator >= DT && steps < MAX_FRAME_STEPS {
+            self.update();
+            self.accumulator -= DT;
+            steps += 1;
+        }
+        if steps == MAX_FRAME_STEPS {
+            self.accumulator = 0.0;
+        }
+
+        self.accumulator / DT
+    }
+
+    // Pushes the player out of any Solid block it's overlapping horizontally.
+    fn resolve_x(&mut self) {
+        for block in &self.blocks {
+            if block.kind != BlockKind::Solid {
+                continue;
+            }
+            let vertical_overlap = self.player.y + self.player.height > block.y
+                && self.player.y < block.y + block.height;
+            let horizontal_overlap = self.player.x + self.player.width > block.x
+                && self.player.x < block.x + block.width;
+            if !vertical_overlap || !horizontal_overlap {
+                continue;
+            }
+            if self.player.velocity_x > 0.0 {
+                self.player.x = block.x - self.player.width;
+            } else if self.player.velocity_x < 0.0 {
+                self.player.x = block.x + block.width;
+            }
+            self.player.velocity_x = 0.0;
+        }
+    }
+
+    // Lands the player on top of a block after moving on Y. OneWay blocks
+    // only catch a player that was above the block top last step, so jumping
+    // up through one still works.
+    fn resolve_y(&mut self) {
+        let prev_bottom = self.player.prev_y + self.player.height;
+        for block in &self.blocks {
+            let horizontal_overlap = self.player.x + self.player.width > block.x
+                && self.player.x < block.x + block.width;
+            if !horizontal_overlap || self.player.velocity_y < 0.0 {
+                continue;
+            }
+
+            let surface_y = block.surface_y_at(self.player.x + self.player.width / 2.0);
+            let player_bottom = self.player.y + self.player.height;
+            if prev_bottom <= surface_y && player_bottom >= surface_y {
+                self.player.y = surface_y - self.player.height;
+                self.player.velocity_y = 0.0;
+                if self.player.is_jumping {
+                    self.player.is_jumping = false;
+                    play_sound("land");
+                }
+            }
+        }
     }
 
     fn update(&mut self) {
@@ -109,20 +405,31 @@ impl Game {
             return;
         }
 
-        let gravity = 0.5;
-        let move_speed = 5.0;
+        self.player.prev_x = self.player.x;
+        self.player.prev_y = self.player.y;
+
+        let gravity = self.config.gravity;
+        let move_speed = self.config.move_speed;
 
-        if self.player.is_moving_left {
+        let moving_left = self.player.keyboard_left || self.gamepad_left;
+        let moving_right = self.player.keyboard_right || self.gamepad_right;
+        if moving_left {
             self.player.velocity_x = -move_speed;
-        } else if self.player.is_moving_right {
+        } else if moving_right {
             self.player.velocity_x = move_speed;
         } else {
             self.player.velocity_x = 0.0;
         }
 
         self.player.velocity_y += gravity;
+
+        // Move and resolve X first, then Y, so a block's side can't be
+        // crossed diagonally before its top gets a chance to catch the player.
         self.player.x += self.player.velocity_x;
+        self.resolve_x();
+
         self.player.y += self.player.velocity_y;
+        self.resolve_y();
 
         // Wall collision
         if self.player.x < 0.0 {
@@ -132,20 +439,6 @@ impl Game {
             self.player.x = self.width as f64 - self.player.width;
         }
 
-        // Block collision
-        for block in &self.blocks {
-            let player_bottom = self.player.y + self.player.height;
-            if self.player.velocity_y > 0.0 &&
-               self.player.x < block.x + block.width &&
-               self.player.x + self.player.width > block.x &&
-               player_bottom >= block.y &&
-               player_bottom <= block.y + self.player.velocity_y {
-                self.player.y = block.y - self.player.height;
-                self.player.velocity_y = 0.0;
-                self.player.is_jumping = false;
-            }
-        }
-
         // Camera follow
         if self.player.y - self.camera_y < self.height as f64 / 2.0 {
             self.camera_y = self.player.y - self.height as f64 / 2.0;
@@ -154,6 +447,7 @@ impl Game {
         // Game Over condition
         if self.player.y - self.camera_y > self.height as f64 {
             self.state = GameState::GameOver;
+            play_sound("gameover");
         }
 
         // Update score
@@ -162,32 +456,14 @@ impl Game {
             self.score = new_score;
         }
 
-        // Generate new blocks
+        // Generate new blocks, harder as the score climbs
         if self.blocks.last().unwrap().y - self.camera_y > -100.0 {
-            let last_block = self.blocks.last().unwrap();
-            let last_x = last_block.x;
-            let y = last_block.y - 120.0;
-
-            // Ensure the next block is reachable and not at the screen edges
-            let max_jump_dist = 200.0; // Max horizontal distance player can jump
-            let relative_min_x = last_x - max_jump_dist;
-            let relative_max_x = last_x + last_block.width + max_jump_dist;
-
-            let screen_margin = 50.0;
-            let screen_min_x = screen_margin;
-            let screen_max_x = self.width as f64 - 100.0 - screen_margin; // 100 is block width
-
-            let min_x = relative_min_x.max(screen_min_x);
-            let max_x = relative_max_x.min(screen_max_x);
-
-            let x = if min_x < max_x {
-                min_x + js_sys::Math::random() * (max_x - min_x)
-            } else {
-                // Fallback to center if the range is invalid
-                (self.width / 2 - 50) as f64
+            let difficulty = self.score as f64;
+            let new_block = {
+                let last_block = self.blocks.last().unwrap();
+                self.generator.next_block(last_block, difficulty)
             };
-
-            self.blocks.push(Block::new(x, y, 100.0, 20.0));
+            self.blocks.push(new_block);
         }
 
         // Remove old blocks
@@ -196,7 +472,7 @@ impl Game {
         self.blocks.retain(|block| block.y - camera_y < height);
     }
 
-    fn draw(&self) {
+    fn draw(&self, alpha: f64) {
         self.context.set_fill_style_str("#87CEEB"); // Sky blue background
         self.context.fill_rect(0.0, 0.0, self.width as f64, self.height as f64);
 
@@ -204,13 +480,17 @@ impl Game {
             block.draw(&self.context, self.camera_y);
         }
 
-        self.player.draw(&self.context, self.camera_y);
+        self.player.draw(&self.context, self.camera_y, alpha);
 
         self.context.set_fill_style_str("black");
         self.context.set_font("24px Arial");
         self.context.set_text_align("start"); // Reset text alignment
         self.context.fill_text(&format!("Score: {}", self.score), 10.0, 30.0).unwrap();
 
+        if self.debug {
+            self.draw_debug_overlay();
+        }
+
         if self.state == GameState::GameOver {
             self.context.set_fill_style_str("rgba(0, 0, 0, 0.5)");
             self.context.fill_rect(0.0, 0.0, self.width as f64, self.height as f64);
@@ -226,29 +506,88 @@ impl Game {
         }
     }
 
+    // Toggled by backtick/F3: draws collision boxes, the reachability window, and live stats.
+    fn draw_debug_overlay(&self) {
+        self.context.set_stroke_style_str("red");
+        self.context.set_line_width(1.0);
+        for block in &self.blocks {
+            self.context.stroke_rect(block.x, block.y - self.camera_y, block.width, block.height);
+        }
+
+        self.context.set_stroke_style_str("lime");
+        self.context.stroke_rect(self.player.x, self.player.y - self.camera_y, self.player.width, self.player.height);
+
+        if let Some((min_x, max_x, y)) = self.generator.last_window {
+            self.context.set_stroke_style_str("yellow");
+            self.context.stroke_rect(min_x, y - self.camera_y, max_x - min_x, self.config.block_height);
+        }
+
+        self.context.set_fill_style_str("black");
+        self.context.set_font("14px monospace");
+        self.context.set_text_align("start");
+        let lines = [
+            format!("FPS: {:.0}", self.fps),
+            format!("player: x={:.1} y={:.1}", self.player.x, self.player.y),
+            format!("velocity: x={:.2} y={:.2}", self.player.velocity_x, self.player.velocity_y),
+            format!("blocks: {}", self.blocks.len()),
+            format!("camera_y: {:.1}", self.camera_y),
+            format!("seed: {}", self.seed),
+        ];
+        for (i, line) in lines.iter().enumerate() {
+            self.context.fill_text(line, 10.0, 56.0 + i as f64 * 16.0).unwrap();
+        }
+    }
+
     fn start_move(&mut self, direction: &str) {
         if self.state != GameState::Playing {
             return;
         }
         match direction {
-            "left" => self.player.is_moving_left = true,
-            "right" => self.player.is_moving_right = true,
+            "left" => self.player.keyboard_left = true,
+            "right" => self.player.keyboard_right = true,
             _ => {}
         }
     }
 
     fn stop_move(&mut self, direction: &str) {
         match direction {
-            "left" => self.player.is_moving_left = false,
-            "right" => self.player.is_moving_right = false,
+            "left" => self.player.keyboard_left = false,
+            "right" => self.player.keyboard_right = false,
             _ => {}
         }
     }
 
     fn jump(&mut self) {
         if self.state == GameState::Playing && !self.player.is_jumping {
-            self.player.velocity_y = -15.0;
+            self.player.velocity_y = self.config.jump_velocity;
             self.player.is_jumping = true;
+            play_sound("jump");
+        }
+    }
+
+    // Polled once per frame rather than event-driven, since the Gamepad API
+    // only exposes a snapshot. Sets gamepad_left/right directly (separate
+    // from the keyboard's flags) so update() can OR the two sources instead
+    // of one clobbering the other.
+    fn poll_gamepad(&mut self, navigator: &Navigator) {
+        let pad = navigator
+            .get_gamepads()
+            .ok()
+            .and_then(|pads| (0..pads.length()).find_map(|i| pads.get(i).dyn_into::<Gamepad>().ok()));
+        let Some(pad) = pad else {
+            self.gamepad_left = false;
+            self.gamepad_right = false;
+            return;
+        };
+
+        let axis_x = pad.axes().get(0).as_f64().unwrap_or(0.0);
+        self.gamepad_left = axis_x < -GAMEPAD_DEADZONE;
+        self.gamepad_right = axis_x > GAMEPAD_DEADZONE;
+
+        if let Ok(south) = pad.buttons().get(0).dyn_into::<GamepadButton>() {
+            if south.pressed() {
+                self.jump();
+            }
         }
     }
 }
@@ -256,6 +595,45 @@ impl Game {
 // Use a global mutable state for the game
 thread_local! {
     static GAME: Rc<RefCell<Option<Game>>> = Rc::new(RefCell::new(None));
+    static AUDIO: Rc<Option<Audio>> = Rc::new(Audio::new());
+}
+
+fn play_sound(name: &str) {
+    AUDIO.with(|audio| {
+        if let Some(audio) = audio.as_ref() {
+            audio.play(name);
+        }
+    });
+}
+
+fn resume_audio() {
+    AUDIO.with(|audio| {
+        if let Some(audio) = audio.as_ref() {
+            audio.resume();
+        }
+    });
+}
+
+// Decodes a sample from raw bytes and caches it under `name` for Audio::play.
+// Decoding is async, so the buffer becomes available shortly after this returns.
+#[wasm_bindgen]
+pub fn load_sound(name: String, bytes: Vec<u8>) -> Result<(), JsValue> {
+    let audio_rc = AUDIO.with(|audio| audio.clone());
+    let Some(audio) = audio_rc.as_ref() else {
+        return Ok(());
+    };
+    let array = js_sys::Uint8Array::from(bytes.as_slice());
+    let decode_promise = audio.context.decode_audio_data(&array.buffer())?;
+
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(value) = wasm_bindgen_futures::JsFuture::from(decode_promise).await {
+            if let Some(audio) = audio_rc.as_ref() {
+                audio.buffers.borrow_mut().insert(name, value.unchecked_into());
+            }
+        }
+    });
+
+    Ok(())
 }
 
 #[wasm_bindgen]
@@ -296,12 +674,14 @@ pub fn jump() {
 
 #[wasm_bindgen]
 pub fn handle_key_down(event: KeyboardEvent) {
+    resume_audio();
     GAME.with(|game_rc| {
         if let Some(game) = &mut *game_rc.borrow_mut() {
             match event.key().as_str() {
                 "ArrowLeft" => game.start_move("left"),
                 "ArrowRight" => game.start_move("right"),
                 " " | "ArrowUp" => game.jump(),
+                "`" | "F3" => game.debug = !game.debug,
                 _ => {}
             }
         }
@@ -323,19 +703,48 @@ pub fn handle_key_up(event: KeyboardEvent) {
 
 #[wasm_bindgen]
 pub fn handle_click() {
+    resume_audio();
     GAME.with(|game_rc| {
         if let Some(game) = &mut *game_rc.borrow_mut() {
             if game.state == GameState::GameOver {
-                // Reset the game by creating a new instance
-                let new_game = Game::new(game.context.clone(), game.width, game.height);
+                // Reset the game by creating a new instance, keeping the config it was started with
+                let new_game = Game::new(game.context.clone(), game.width, game.height, game.config.clone(), random_seed());
                 *game = new_game;
             }
         }
     });
 }
 
+// Lets a player share/replay a run via its seed.
+#[wasm_bindgen]
+pub fn current_seed() -> String {
+    GAME.with(|game_rc| match &*game_rc.borrow() {
+        Some(game) => game.seed.to_string(),
+        None => String::new(),
+    })
+}
+
+fn random_seed() -> u64 {
+    let hi = (js_sys::Math::random() * (u32::MAX as f64)) as u64;
+    let lo = (js_sys::Math::random() * (u32::MAX as f64)) as u64;
+    (hi << 32) | lo
+}
+
+// Pass a previously reported `current_seed()` to replay that run, or `None` for a fresh one.
+#[wasm_bindgen]
+pub fn start_game(width: u32, height: u32, seed: Option<u64>) -> Result<(), JsValue> {
+    init_game(width, height, Config::default(), seed)
+}
+
+// Like start_game, but loads gameplay tuning (and optionally an authored block layout) from JSON5.
 #[wasm_bindgen]
-pub fn start_game(width: u32, height: u32) -> Result<(), JsValue> {
+pub fn start_game_with_config(width: u32, height: u32, config_json: String, seed: Option<u64>) -> Result<(), JsValue> {
+    let config: Config = json5::from_str(&config_json)
+        .map_err(|e| JsValue::from_str(&format!("invalid config: {}", e)))?;
+    init_game(width, height, config, seed)
+}
+
+fn init_game(width: u32, height: u32, config: Config, seed: Option<u64>) -> Result<(), JsValue> {
     utils::set_panic_hook();
 
     let window = web_sys::window().expect("no global `window` exists");
@@ -351,19 +760,24 @@ pub fn start_game(width: u32, height: u32) -> Result<(), JsValue> {
         .dyn_into::<CanvasRenderingContext2d>()?;
 
     // Initialize the game state with initial canvas size
+    let seed = seed.unwrap_or_else(random_seed);
     GAME.with(|game_rc| {
-        *game_rc.borrow_mut() = Some(Game::new(context, width, height));
+        *game_rc.borrow_mut() = Some(Game::new(context, width, height, config, seed));
     });
 
+    let performance = window.performance().expect("performance should be available");
+
     let f: Rc<RefCell<Option<Closure<dyn FnMut()>>>> = Rc::new(RefCell::new(None));
     let g = f.clone();
 
     let game_loop_window = window.clone();
     *g.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+        let now = performance.now();
         GAME.with(|game_rc| {
             if let Some(game) = &mut *game_rc.borrow_mut() {
-                game.update();
-                game.draw();
+                game.poll_gamepad(&game_loop_window.navigator());
+                let alpha = game.advance(now);
+                game.draw(alpha);
             }
         });
 
@@ -374,4 +788,40 @@ pub fn start_game(width: u32, height: u32) -> Result<(), JsValue> {
     window.request_animation_frame(initial_f.as_ref().unwrap().as_ref().unchecked_ref())?;
 
     Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_block_is_deterministic_for_a_given_seed() {
+        let config = Config::default();
+        let last = Block::new(0.0, 0.0, config.block_width, config.block_height, BlockKind::Solid);
+
+        let mut a = LevelGenerator::new(42, 800, config.clone());
+        let mut b = LevelGenerator::new(42, 800, config);
+        let next_a = a.next_block(&last, 0.0);
+        let next_b = b.next_block(&last, 0.0);
+
+        assert_eq!(next_a.x, next_b.x);
+        assert_eq!(next_a.y, next_b.y);
+    }
+
+    #[test]
+    fn next_block_never_jumps_further_than_max_jump_dist() {
+        let config = Config::default();
+        let max_jump_dist = config.max_jump_dist;
+        let mut generator = LevelGenerator::new(1234, 800, config.clone());
+        let mut last = Block::new(0.0, 0.0, config.block_width, config.block_height, BlockKind::Solid);
+
+        for _ in 0..100 {
+            let next = generator.next_block(&last, 0.0);
+            let gap = (next.x - (last.x + last.width))
+                .max(last.x - (next.x + next.width))
+                .max(0.0);
+            assert!(gap <= max_jump_dist, "gap {} exceeded max_jump_dist {}", gap, max_jump_dist);
+            last = next;
+        }
+    }
 }
\ No newline at end of file